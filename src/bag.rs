@@ -2,7 +2,7 @@ use rand::seq::SliceRandom;
 use rand::thread_rng;
 
 use crate::matrix::Matrix;
-use crate::piece::Piece;
+use crate::piece::{Piece, PieceKind};
 
 #[inline]
 pub fn unwrap_abort<T>(o: Option<T>) -> T {
@@ -13,64 +13,64 @@ pub fn unwrap_abort<T>(o: Option<T>) -> T {
   }
 }
 
-#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Bag {
   pieces: Vec<Piece>,
 }
 
 fn shuffled_pieces() -> Vec<Piece> {
   #[rustfmt::skip]
-  let mut matrices = vec![
+  let mut templates = vec![
       // I
-      vec![
+      (PieceKind::I, vec![
           vec![0, 0, 0, 0],
           vec![1, 1, 1, 1],
           vec![0, 0, 0, 0],
           vec![0, 0, 0, 0]
-      ],
+      ]),
       // J
-      vec![
+      (PieceKind::J, vec![
           vec![2, 0, 0],
           vec![2, 2, 2],
           vec![0, 0, 0]
-      ],
+      ]),
       // L
-      vec![
+      (PieceKind::L, vec![
           vec![0, 0, 3],
           vec![3, 3, 3],
           vec![0, 0, 0]
-      ],
+      ]),
       // O
-      vec![
+      (PieceKind::O, vec![
           vec![4, 4],
           vec![4, 4],
-      ],
+      ]),
       // S
-      vec![
+      (PieceKind::S, vec![
           vec![0, 5, 5],
           vec![5, 5, 0],
           vec![0, 0, 0]
-      ],
+      ]),
       // T
-      vec![
+      (PieceKind::T, vec![
           vec![0, 6, 0],
           vec![6, 6, 6],
           vec![0, 0, 0]
-      ],
+      ]),
       // Z
-      vec![
+      (PieceKind::Z, vec![
           vec![7, 7, 0],
           vec![0, 7, 7],
           vec![0, 0, 0]
-      ],
+      ]),
   ];
 
-  // Randomize matrices
-  matrices.shuffle(&mut thread_rng());
+  // Randomize templates
+  templates.shuffle(&mut thread_rng());
 
-  matrices
+  templates
     .into_iter()
-    .map(|matrix| Piece::new(Matrix::from_vec(matrix)))
+    .map(|(kind, matrix)| Piece::new(kind, Matrix::from_vec(matrix)))
     .collect()
 }
 
@@ -0,0 +1,228 @@
+use crate::matrix::Matrix;
+
+/// One board row packed into a single integer, bit `j` set when column `j`
+/// is occupied. `col_count` is always small enough (<= 32) for this to fit.
+pub type RowMask = u32;
+
+fn full_mask(col_count: u8) -> RowMask {
+  if col_count >= 32 {
+    RowMask::MAX
+  } else {
+    (1 << col_count) - 1
+  }
+}
+
+// Shift `row_mask` (local to a piece, column 0 == leftmost) so its bits line
+// up with board columns starting at `x`. Returns None if any set bit would
+// land left or right of the board, so wall bounds fall out of the same check
+// as collision with other blocks.
+fn shifted_row(row_mask: RowMask, x: i8, col_count: u8) -> Option<RowMask> {
+  let full = full_mask(col_count);
+  if x >= 0 {
+    let shifted = (row_mask as u64) << (x as u32);
+    if shifted & !(full as u64) != 0 {
+      None
+    } else {
+      Some(shifted as RowMask)
+    }
+  } else {
+    let shift = (-x) as u32;
+    if shift >= RowMask::BITS || row_mask & ((1 << shift) - 1) != 0 {
+      None
+    } else {
+      Some(row_mask >> shift)
+    }
+  }
+}
+
+/// A piece reduced to per-row bit masks plus its cell colors, so it can be
+/// tested against a `BitBoard` with bitwise ANDs instead of set operations.
+#[derive(Clone, Debug)]
+pub struct PieceMask {
+  pub origin: (i8, i8),
+  pub col_count: u8,
+  row_masks: Vec<RowMask>,
+  colors: Vec<u8>,
+}
+
+impl From<&Matrix> for PieceMask {
+  fn from(matrix: &Matrix) -> PieceMask {
+    let rows = matrix.to_vec();
+    let row_masks = rows
+      .iter()
+      .map(|row| {
+        row
+          .iter()
+          .enumerate()
+          .filter(|(_, &cell)| cell != 0)
+          .fold(0, |mask, (j, _)| mask | (1 << j))
+      })
+      .collect();
+    PieceMask {
+      origin: matrix.origin,
+      col_count: matrix.col_count,
+      row_masks,
+      colors: rows.into_iter().flatten().collect(),
+    }
+  }
+}
+
+/// The board's occupancy grid: one row mask per row for fast collision and
+/// line-clear checks, plus a side array of colors (same row-major layout as
+/// `Matrix`) so the bitboard can still be rendered through `cells_ptr`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BitBoard {
+  pub row_count: u8,
+  pub col_count: u8,
+  rows: Vec<RowMask>,
+  colors: Vec<u8>,
+}
+
+impl BitBoard {
+  pub fn new(row_count: u8, col_count: u8) -> BitBoard {
+    BitBoard {
+      row_count,
+      col_count,
+      rows: vec![0; row_count as usize],
+      colors: vec![0; row_count as usize * col_count as usize],
+    }
+  }
+  pub fn collides(&self, piece: &PieceMask) -> bool {
+    let (x, y) = piece.origin;
+    for (r, &row_mask) in piece.row_masks.iter().enumerate() {
+      if row_mask == 0 {
+        continue;
+      }
+      let board_row = y as i32 + r as i32;
+      if board_row < 0 {
+        // Above the board: only the horizontal bounds matter.
+        if shifted_row(row_mask, x, self.col_count).is_none() {
+          return true;
+        }
+        continue;
+      }
+      if board_row >= self.row_count as i32 {
+        return true;
+      }
+      match shifted_row(row_mask, x, self.col_count) {
+        None => return true,
+        Some(shifted) => {
+          if self.rows[board_row as usize] & shifted != 0 {
+            return true;
+          }
+        }
+      }
+    }
+    false
+  }
+  pub fn add(&mut self, piece: &PieceMask) {
+    let (x, y) = piece.origin;
+    for (r, &row_mask) in piece.row_masks.iter().enumerate() {
+      if row_mask == 0 {
+        continue;
+      }
+      let board_row = y as i32 + r as i32;
+      if board_row < 0 || board_row >= self.row_count as i32 {
+        continue;
+      }
+      if let Some(shifted) = shifted_row(row_mask, x, self.col_count) {
+        self.rows[board_row as usize] |= shifted;
+      }
+      for j in 0..piece.col_count as i32 {
+        let color = piece.colors[r * piece.col_count as usize + j as usize];
+        if color == 0 {
+          continue;
+        }
+        let board_col = x as i32 + j;
+        if board_col >= 0 && board_col < self.col_count as i32 {
+          let idx = board_row as usize * self.col_count as usize + board_col as usize;
+          self.colors[idx] = color;
+        }
+      }
+    }
+  }
+  // Drop any full rows, shifting everything above them down, and return how
+  // many rows were cleared.
+  pub fn clear_full_rows(&mut self) -> u8 {
+    let full = full_mask(self.col_count);
+    let col_count = self.col_count as usize;
+    let mut kept_rows = Vec::with_capacity(self.row_count as usize);
+    let mut kept_colors = Vec::with_capacity(self.colors.len());
+    for i in 0..self.row_count as usize {
+      if self.rows[i] != full {
+        kept_rows.push(self.rows[i]);
+        kept_colors.extend_from_slice(&self.colors[i * col_count..(i + 1) * col_count]);
+      }
+    }
+    let cleared = self.row_count as usize - kept_rows.len();
+    let mut rows = vec![0; cleared];
+    rows.extend(kept_rows);
+    let mut colors = vec![0; cleared * col_count];
+    colors.extend(kept_colors);
+    self.rows = rows;
+    self.colors = colors;
+    cleared as u8
+  }
+  // The topmost occupied row, used to position a newly spawned piece.
+  pub fn min_y(&self) -> Option<i8> {
+    self.rows.iter().position(|&row| row != 0).map(|i| i as i8)
+  }
+  pub fn to_vec(&self) -> Vec<Vec<u8>> {
+    self
+      .colors
+      .chunks(self.col_count as usize)
+      .map(|row| row.to_vec())
+      .collect()
+  }
+  pub fn cells_ptr(&self) -> *const u8 {
+    self.colors.as_ptr()
+  }
+}
+
+// Hard-drop `matrix` straight down against `board` and return the resting
+// origin, one step short of the first colliding position.
+pub fn drop_origin(board: &BitBoard, matrix: &Matrix) -> (i8, i8) {
+  let mut candidate = matrix.clone();
+  let mut collision = false;
+  while !collision {
+    candidate.origin.1 += 1;
+    collision = board.collides(&PieceMask::from(&candidate));
+  }
+  (candidate.origin.0, candidate.origin.1 - 1)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn piece(rows: Vec<Vec<u8>>, origin: (i8, i8)) -> PieceMask {
+    let mut matrix = Matrix::from_vec(rows);
+    matrix.origin = origin;
+    PieceMask::from(&matrix)
+  }
+
+  #[test]
+  fn test_collides_with_existing_block() {
+    let mut board = BitBoard::new(4, 4);
+    board.add(&piece(vec![vec![1, 1]], (0, 3)));
+    assert!(board.collides(&piece(vec![vec![1]], (0, 3))));
+    assert!(!board.collides(&piece(vec![vec![1]], (2, 3))));
+  }
+
+  #[test]
+  fn test_collides_with_walls_and_floor() {
+    let board = BitBoard::new(4, 4);
+    assert!(board.collides(&piece(vec![vec![1]], (-1, 0))));
+    assert!(board.collides(&piece(vec![vec![1]], (4, 0))));
+    assert!(board.collides(&piece(vec![vec![1]], (0, 4))));
+    assert!(!board.collides(&piece(vec![vec![1]], (0, 3))));
+  }
+
+  #[test]
+  fn test_clear_full_rows() {
+    let mut board = BitBoard::new(3, 2);
+    board.add(&piece(vec![vec![1, 1]], (0, 2)));
+    assert_eq!(board.clear_full_rows(), 1);
+    assert_eq!(board.to_vec(), vec![vec![0, 0], vec![0, 0], vec![0, 0]]);
+  }
+}
@@ -1,14 +1,14 @@
-use crate::matrix::Matrix;
+use crate::bitboard::BitBoard;
 
-#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Board {
-  pub matrix: Matrix,
+  pub matrix: BitBoard,
 }
 
 impl Board {
   pub fn new(row_count: u8, col_count: u8) -> Board {
     Board {
-      matrix: Matrix::from_vec(vec![vec![0; col_count as usize]; row_count as usize]),
+      matrix: BitBoard::new(row_count, col_count),
     }
   }
 }
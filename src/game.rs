@@ -1,10 +1,14 @@
 use crate::bag::Bag;
+use crate::bitboard::{self, PieceMask};
 use crate::board::Board;
+use crate::kicks;
 use crate::matrix::Matrix;
 use crate::piece::Piece;
+use crate::planner;
+use crate::solver::{self, Placement};
 // use crate::utils::set_panic_hook;
 
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 use std::fmt;
 use wasm_bindgen::prelude::*;
 
@@ -14,6 +18,7 @@ pub enum Cmd {
   Left,
   Right,
   Rotate,
+  SoftDrop,
   Drop,
 }
 
@@ -31,6 +36,9 @@ pub struct Game {
   input_rate: f32,
   last_input_time: f32,
   last_drop_time: f32,
+  autopilot: bool,
+  autopilot_target: Option<Placement>,
+  autopilot_plan: VecDeque<Cmd>,
 }
 
 fn next_piece(bag: &mut Bag, board: &Board) -> Piece {
@@ -70,59 +78,55 @@ impl Game {
       drop_speed: 0.5,
       last_drop_time: 0.0,
       game_over: false,
+      autopilot: false,
+      autopilot_target: None,
+      autopilot_plan: VecDeque::new(),
     }
   }
   #[wasm_bindgen(js_name = pushCommand)]
   pub fn push_command(&mut self, cmd: Cmd) {
     self.commands.insert(cmd);
   }
+  #[wasm_bindgen(js_name = setAutopilot)]
+  pub fn set_autopilot(&mut self, enabled: bool) {
+    self.autopilot = enabled;
+    self.autopilot_target = None;
+    self.autopilot_plan.clear();
+  }
   pub fn update(&mut self, dt: f32) {
     if self.game_over {
       return;
     }
     self.elapsed_time += dt;
-    let row_count = self.board.matrix.row_count as i8;
-    let col_count = self.board.matrix.col_count as i8;
-    let row_max = row_count - 1;
-    let col_max = col_count - 1;
     let input_timeout = (self.elapsed_time - self.last_input_time) > self.input_rate;
     let drop_timeout = (self.elapsed_time - self.last_drop_time) > self.drop_speed;
 
+    if self.autopilot && !self.piece.at_rest {
+      self.push_autopilot_command();
+    }
+
     // User input
     if input_timeout {
       for cmd in self.commands.iter() {
         let mut next_matrix = self.piece.matrix.clone();
-        let piece_min_x = self.piece.matrix.min_x().unwrap();
-        let piece_max_x = self.piece.matrix.max_x().unwrap();
         match cmd {
           Cmd::Rotate => {
-            next_matrix = Matrix::rotate_right(&self.piece.matrix);
-            // Check if the rotated piece in in bounds
-            let rot_piece_min_x = next_matrix.min_x().unwrap();
-            let rot_piece_max_x = next_matrix.max_x().unwrap();
-            let mut next_x = if rot_piece_min_x < 0 {
-              // Move to right by offset
-              next_matrix.origin.0 + rot_piece_min_x.abs()
-            } else if rot_piece_max_x > col_max {
-              // Move to left by offset
-              next_matrix.origin.0 - (rot_piece_max_x - col_max)
-            } else {
-              // No change necessary
-              next_matrix.origin.0
-            };
-            // Apply horizontal resolution
-            next_x += next_matrix.resolution_x(&self.board.matrix);
-            next_matrix.origin = (next_x, next_matrix.origin.1);
+            let from_state = self.piece.rotation_state;
+            let to_state = (from_state + 1) % 4;
+            let rotated_shape = Matrix::rotate_right(&self.piece.matrix);
+            if let Some(kicked) = self.resolve_rotation(&rotated_shape, from_state, to_state) {
+              next_matrix = kicked;
+              self.piece.rotation_state = to_state;
+            }
           }
           Cmd::Left => {
-            if piece_min_x > 0 {
-              next_matrix.origin.0 -= 1;
-            }
+            next_matrix.origin.0 -= 1;
           }
           Cmd::Right => {
-            if piece_max_x < col_max {
-              next_matrix.origin.0 += 1;
-            }
+            next_matrix.origin.0 += 1;
+          }
+          Cmd::SoftDrop => {
+            next_matrix.origin.1 += 1;
           }
           Cmd::Drop => {
             self.piece.matrix.origin = self.drop_pos();
@@ -135,7 +139,7 @@ impl Game {
           break;
         }
 
-        if !(next_matrix.collides(&self.board.matrix)) {
+        if !self.collides(&next_matrix) {
           // Update piece
           self.piece.matrix = next_matrix;
         }
@@ -149,9 +153,7 @@ impl Game {
       let mut v_next_matrix = self.piece.matrix.clone();
       v_next_matrix.origin.1 += 1;
 
-      let v_next_max_y = v_next_matrix.max_y().unwrap_or(0);
-
-      if v_next_max_y > row_max || v_next_matrix.collides(&self.board.matrix) {
+      if self.collides(&v_next_matrix) {
         // Piece is at rest
         self.piece.at_rest = true;
       } else {
@@ -162,7 +164,7 @@ impl Game {
 
     if self.piece.at_rest {
       // Add to board
-      self.board.matrix.add(&self.piece.matrix);
+      self.board.matrix.add(&PieceMask::from(&self.piece.matrix));
       // Check if piece is at top
       self.game_over = match self.piece.matrix.min_y() {
         Some(min_y) => min_y <= 0,
@@ -172,31 +174,58 @@ impl Game {
       self.score += 1;
       if !self.game_over {
         self.piece = next_piece(&mut self.bag, &self.board);
+        self.autopilot_target = None;
+        self.autopilot_plan.clear();
       }
     }
 
-    // Update board
-    let mut board_vec = self.board.matrix.to_vec();
-    // Remove full rows from the board
-    board_vec.retain(|row| row.iter().any(|&cell| cell == 0));
+    // Clear full rows and award points for them
+    let cleared_rows = self.board.matrix.clear_full_rows();
+    self.score += (cleared_rows as u32) * 10;
+  }
+  // Steer the current piece one step closer to the best-scoring placement,
+  // queuing a command the same way a player's input would be. The move
+  // sequence is planned once per piece via `plan_to`, so the autopilot can
+  // reach tucks and spins that a straight hard drop would miss.
+  fn push_autopilot_command(&mut self) {
+    if self.autopilot_target.is_none() {
+      let target = solver::best_placement(&self.board, &self.piece);
+      self.autopilot_plan = match target {
+        Some(placement) => self
+          .plan_to(placement.column as u8, placement.rotation_state)
+          .into(),
+        None => VecDeque::new(),
+      };
+      self.autopilot_target = target;
+    }
 
-    let new_row_count = row_count as usize - board_vec.len();
-    for _ in 0..new_row_count {
-      board_vec.insert(0, vec![0; col_count as usize]);
+    let cmd = self.autopilot_plan.pop_front().unwrap_or(Cmd::Drop);
+    self.commands.insert(cmd);
+  }
+  // Try each SRS kick offset for the given rotation transition in order,
+  // returning the first placement that doesn't collide with the board.
+  fn resolve_rotation(&self, rotated_shape: &Matrix, from: u8, to: u8) -> Option<Matrix> {
+    let offsets = kicks::offsets_for(self.piece.kind, from, to);
+    for &(dx, dy) in offsets {
+      let mut candidate = rotated_shape.clone();
+      candidate.origin = (
+        self.piece.matrix.origin.0 + dx,
+        self.piece.matrix.origin.1 + dy,
+      );
+      if !self.collides(&candidate) {
+        return Some(candidate);
+      }
     }
-    self.board.matrix = Matrix::from_vec(board_vec);
-    // 10 points for each row
-    self.score += (new_row_count * 10) as u32;
+    None
+  }
+  // Whether the given piece placement collides with the board or goes out
+  // of bounds; the bitboard's collision check folds walls and the floor
+  // into the same check as occupied cells.
+  fn collides(&self, matrix: &Matrix) -> bool {
+    self.board.matrix.collides(&PieceMask::from(matrix))
   }
   fn drop_pos(&self) -> (i8, i8) {
-    let mut matrix = self.piece.matrix.clone();
-    let mut collision = false;
-    let row_max = self.board.matrix.row_count as i8 - 1;
-    while !collision {
-      matrix.origin.1 += 1;
-      collision = matrix.max_y().unwrap_or(0) > row_max || matrix.collides(&self.board.matrix);
-    }
-    (matrix.origin.0, matrix.origin.1 - 1)
+    bitboard::drop_origin(&self.board.matrix, &self.piece.matrix)
   }
 
   #[wasm_bindgen(js_name = boardCellsPtr)]
@@ -226,13 +255,22 @@ impl Game {
   }
 }
 
+impl Game {
+  // The shortest Left/Right/Rotate/SoftDrop sequence that carries the
+  // current piece from where it is now to a resting placement at board
+  // column `column` in SRS rotation state `rotation`. Empty if unreachable.
+  pub fn plan_to(&self, column: u8, rotation: u8) -> Vec<Cmd> {
+    planner::plan_to(&self.board, &self.piece, column as i8, rotation)
+  }
+}
+
 impl fmt::Display for Game {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    let mut state = Matrix::from_vec(self.board.matrix.to_vec());
+    let mut state = self.board.matrix.clone();
     let mut output = String::new();
 
     if !self.game_over {
-      state.add(&self.piece.matrix);
+      state.add(&PieceMask::from(&self.piece.matrix));
     }
 
     for row in state.to_vec().iter() {
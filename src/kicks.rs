@@ -0,0 +1,158 @@
+use crate::piece::PieceKind;
+
+/// A candidate (dx, dy) nudge applied to a piece's origin after a rotation,
+/// tried in order until one lands somewhere that doesn't collide.
+pub type Offset = (i8, i8);
+
+const NO_KICK: [Offset; 1] = [(0, 0)];
+
+// JLSTZ pieces share one kick table, keyed by the (from, to) rotation state
+// transition. States are 0 (spawn), 1 (R), 2 (2), 3 (L). Offsets are in the
+// board's (dx, dy) convention, where +y is down.
+const JLSTZ_0R: [Offset; 5] = [(0, 0), (-1, 0), (-1, -1), (0, 2), (-1, 2)];
+const JLSTZ_R0: [Offset; 5] = [(0, 0), (1, 0), (1, 1), (0, -2), (1, -2)];
+const JLSTZ_R2: [Offset; 5] = [(0, 0), (1, 0), (1, 1), (0, -2), (1, -2)];
+const JLSTZ_2R: [Offset; 5] = [(0, 0), (-1, 0), (-1, -1), (0, 2), (-1, 2)];
+const JLSTZ_2L: [Offset; 5] = [(0, 0), (1, 0), (1, -1), (0, 2), (1, 2)];
+const JLSTZ_L2: [Offset; 5] = [(0, 0), (-1, 0), (-1, 1), (0, -2), (-1, -2)];
+const JLSTZ_L0: [Offset; 5] = [(0, 0), (-1, 0), (-1, 1), (0, -2), (-1, -2)];
+const JLSTZ_0L: [Offset; 5] = [(0, 0), (1, 0), (1, -1), (0, 2), (1, 2)];
+
+// The I piece kicks along a distinct table.
+const I_0R: [Offset; 5] = [(0, 0), (-2, 0), (1, 0), (-2, 1), (1, -2)];
+const I_R0: [Offset; 5] = [(0, 0), (2, 0), (-1, 0), (2, -1), (-1, 2)];
+const I_R2: [Offset; 5] = [(0, 0), (-1, 0), (2, 0), (-1, -2), (2, 1)];
+const I_2R: [Offset; 5] = [(0, 0), (1, 0), (-2, 0), (1, 2), (-2, -1)];
+const I_2L: [Offset; 5] = [(0, 0), (2, 0), (-1, 0), (2, -1), (-1, 2)];
+const I_L2: [Offset; 5] = [(0, 0), (-2, 0), (1, 0), (-2, 1), (1, -2)];
+const I_L0: [Offset; 5] = [(0, 0), (1, 0), (-2, 0), (1, 2), (-2, -1)];
+const I_0L: [Offset; 5] = [(0, 0), (-1, 0), (2, 0), (-1, -2), (2, 1)];
+
+fn jlstz_kicks(from: u8, to: u8) -> &'static [Offset] {
+  match (from, to) {
+    (0, 1) => &JLSTZ_0R,
+    (1, 0) => &JLSTZ_R0,
+    (1, 2) => &JLSTZ_R2,
+    (2, 1) => &JLSTZ_2R,
+    (2, 3) => &JLSTZ_2L,
+    (3, 2) => &JLSTZ_L2,
+    (3, 0) => &JLSTZ_L0,
+    (0, 3) => &JLSTZ_0L,
+    _ => &NO_KICK,
+  }
+}
+
+fn i_kicks(from: u8, to: u8) -> &'static [Offset] {
+  match (from, to) {
+    (0, 1) => &I_0R,
+    (1, 0) => &I_R0,
+    (1, 2) => &I_R2,
+    (2, 1) => &I_2R,
+    (2, 3) => &I_2L,
+    (3, 2) => &I_L2,
+    (3, 0) => &I_L0,
+    (0, 3) => &I_0L,
+    _ => &NO_KICK,
+  }
+}
+
+/// The ordered list of offsets to try for a rotation from state `from` to
+/// state `to`, for a piece of the given kind. The O piece never kicks.
+pub fn offsets_for(kind: PieceKind, from: u8, to: u8) -> &'static [Offset] {
+  match kind {
+    PieceKind::O => &NO_KICK,
+    PieceKind::I => i_kicks(from, to),
+    _ => jlstz_kicks(from, to),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_jlstz_offsets_for_each_transition() {
+    assert_eq!(offsets_for(PieceKind::T, 0, 1), &JLSTZ_0R);
+    assert_eq!(offsets_for(PieceKind::T, 1, 0), &JLSTZ_R0);
+    assert_eq!(offsets_for(PieceKind::T, 1, 2), &JLSTZ_R2);
+    assert_eq!(offsets_for(PieceKind::T, 2, 1), &JLSTZ_2R);
+    assert_eq!(offsets_for(PieceKind::T, 2, 3), &JLSTZ_2L);
+    assert_eq!(offsets_for(PieceKind::T, 3, 2), &JLSTZ_L2);
+    assert_eq!(offsets_for(PieceKind::T, 3, 0), &JLSTZ_L0);
+    assert_eq!(offsets_for(PieceKind::T, 0, 3), &JLSTZ_0L);
+  }
+
+  #[test]
+  fn test_jlstz_shared_across_j_l_s_z() {
+    for kind in [PieceKind::J, PieceKind::L, PieceKind::S, PieceKind::Z] {
+      assert_eq!(offsets_for(kind, 0, 1), &JLSTZ_0R);
+    }
+  }
+
+  #[test]
+  fn test_i_offsets_for_each_transition() {
+    assert_eq!(offsets_for(PieceKind::I, 0, 1), &I_0R);
+    assert_eq!(offsets_for(PieceKind::I, 1, 0), &I_R0);
+    assert_eq!(offsets_for(PieceKind::I, 1, 2), &I_R2);
+    assert_eq!(offsets_for(PieceKind::I, 2, 1), &I_2R);
+    assert_eq!(offsets_for(PieceKind::I, 2, 3), &I_2L);
+    assert_eq!(offsets_for(PieceKind::I, 3, 2), &I_L2);
+    assert_eq!(offsets_for(PieceKind::I, 3, 0), &I_L0);
+    assert_eq!(offsets_for(PieceKind::I, 0, 3), &I_0L);
+  }
+
+  #[test]
+  fn test_o_never_kicks() {
+    assert_eq!(offsets_for(PieceKind::O, 0, 1), &NO_KICK);
+    assert_eq!(offsets_for(PieceKind::O, 2, 3), &NO_KICK);
+  }
+
+  #[test]
+  fn test_reverse_transition_negates_offsets() {
+    for &(dx, dy) in &JLSTZ_0R {
+      assert!(JLSTZ_R0.contains(&(-dx, -dy)));
+    }
+    for &(dx, dy) in &I_0R {
+      assert!(I_R0.contains(&(-dx, -dy)));
+    }
+  }
+
+  #[test]
+  fn test_srs_kick_resolves_blocked_rotation() {
+    use crate::bitboard::{BitBoard, PieceMask};
+    use crate::matrix::Matrix;
+
+    let mut board = BitBoard::new(4, 4);
+    // Block the single cell the unshifted (0,0) rotation would land on.
+    let mut blocker = Matrix::from_vec(vec![vec![9]]);
+    blocker.origin = (2, 1);
+    board.add(&PieceMask::from(&blocker));
+
+    #[rustfmt::skip]
+    let spawn = Matrix::from_vec(vec![
+      vec![0, 5, 5],
+      vec![5, 5, 0],
+      vec![0, 0, 0],
+    ]);
+    let rotated = Matrix::rotate_right(&spawn);
+    let offsets = offsets_for(PieceKind::S, 0, 1);
+
+    // The unshifted (first) offset collides with the blocker...
+    let mut unshifted = rotated.clone();
+    unshifted.origin = offsets[0];
+    assert!(board.collides(&PieceMask::from(&unshifted)));
+
+    // ...but trying the table in order finds a later offset that kicks clear.
+    let resolved = offsets.iter().find_map(|&(dx, dy)| {
+      let mut candidate = rotated.clone();
+      candidate.origin = (dx, dy);
+      if board.collides(&PieceMask::from(&candidate)) {
+        None
+      } else {
+        Some(candidate)
+      }
+    });
+    assert!(resolved.is_some());
+    assert_ne!(resolved.unwrap().origin, offsets[0]);
+  }
+}
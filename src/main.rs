@@ -1,8 +1,12 @@
 mod bag;
+mod bitboard;
 mod board;
 mod game;
+mod kicks;
 mod matrix;
 mod piece;
+mod planner;
+mod solver;
 
 const ROW_COUNT: u8 = 12;
 const COL_COUNT: u8 = 12;
@@ -10,9 +10,6 @@ pub struct Matrix {
 
 // Private
 impl Matrix {
-  fn get_index(&self, row: i32, column: i32) -> usize {
-    (row * self.col_count as i32 + column) as usize
-  }
   fn blocks(&self) -> HashSet<(i8, i8)> {
     let mut blocks = HashSet::new();
     let (x, y) = self.origin;
@@ -31,13 +28,6 @@ impl Matrix {
     }
     blocks
   }
-  fn in_bounds(&self, row: i32, col: i32) -> bool {
-    (row >= 0 && row < self.row_count as i32) && (col >= 0 && col < self.col_count as i32)
-  }
-  fn block_value(&self, block: (i8, i8)) -> u8 {
-    let (x, y) = self.origin;
-    self.cells[self.get_index((block.0 - y) as i32, (block.1 - x) as i32)]
-  }
 }
 
 // Public
@@ -82,59 +72,6 @@ impl Matrix {
   pub fn max_y(&self) -> Option<i8> {
     self.blocks().iter().map(|b| b.0).max()
   }
-  pub fn min_x(&self) -> Option<i8> {
-    self.blocks().iter().map(|b| b.1).min()
-  }
-  pub fn max_x(&self) -> Option<i8> {
-    self.blocks().iter().map(|b| b.1).max()
-  }
-  pub fn collides(&self, other: &Matrix) -> bool {
-    self.blocks().intersection(&other.blocks()).count() > 0
-  }
-  pub fn resolution_x(&self, other: &Matrix) -> i8 {
-    let intersection = self
-      .blocks()
-      .intersection(&other.blocks())
-      .cloned()
-      .collect::<HashSet<(i8, i8)>>();
-    if intersection.is_empty() {
-      return 0;
-    }
-
-    let min_self_x = self.min_x().unwrap();
-    let max_self_x = self.max_x().unwrap();
-    let min_other_x = intersection.iter().map(|b| b.1).min().unwrap();
-    let mid_point = min_self_x + ((max_self_x - min_self_x) / 2);
-
-    let mut next_matrix = self.clone();
-    let mut collides = true;
-
-    while collides {
-      // Move out of intersection
-      if min_other_x > mid_point {
-        // Move left
-        next_matrix.origin.0 -= 1;
-      } else {
-        // Move right
-        next_matrix.origin.0 += 1;
-      }
-      collides = next_matrix.blocks().intersection(&intersection).count() > 0
-    }
-
-    next_matrix.origin.0 - self.origin.0
-  }
-  pub fn add(&mut self, other: &Matrix) {
-    // Add to matrix
-    for block in other.blocks() {
-      let world_row = block.0 as i32 - self.origin.1 as i32;
-      let world_col = block.1 as i32 - self.origin.0 as i32;
-
-      if self.in_bounds(world_row, world_col) {
-        let self_idx = self.get_index(world_row, world_col);
-        self.cells[self_idx] = other.block_value(block);
-      }
-    }
-  }
   pub fn to_vec(&self) -> Vec<Vec<u8>> {
     let mut vec = Vec::new();
     let chunks = self.cells.as_slice().chunks(self.col_count as usize);
@@ -214,90 +151,6 @@ mod test {
     assert_eq!(Matrix::rotate_right(&matrix), expected);
   }
 
-  #[test]
-  fn test_collides() {
-    #[rustfmt::skip]
-  let mut a = Matrix::from_vec(vec![
-    vec![0, 0, 0],
-    vec![0, 1, 0],
-    vec![1, 1, 1]
-  ]);
-    #[rustfmt::skip]
-  let b = Matrix::from_vec(vec![
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 1, 1, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-  ]);
-    assert_eq!(a.collides(&b), false);
-    a.origin = (1, 1);
-    assert_eq!(a.collides(&b), true);
-    a.origin = (1, 3);
-    assert_eq!(a.collides(&b), false);
-  }
-
-  #[test]
-  fn test_resolution_x_left() {
-    let a = Matrix::from_vec(vec![vec![1, 1, 1, 1]]);
-    let b = Matrix::from_vec(vec![vec![0, 1, 0, 0, 0, 0]]);
-    assert_eq!(a.resolution_x(&b), 2);
-  }
-
-  #[test]
-  fn test_resolution_x_right() {
-    let a = Matrix::from_vec(vec![vec![1, 1, 1, 1]]);
-    let b = Matrix::from_vec(vec![vec![0, 0, 1, 1, 0, 0]]);
-    assert_eq!(a.resolution_x(&b), -2);
-  }
-
-  #[test]
-  fn test_resolution_x_no_overlap() {
-    let a = Matrix::from_vec(vec![vec![1, 1, 1, 1]]);
-    let b = Matrix::from_vec(vec![vec![0, 0, 0, 0, 0, 0]]);
-    assert_eq!(a.resolution_x(&b), 0);
-  }
-
-  #[test]
-  fn test_resolution_x_multiple_overlap() {
-    #[rustfmt::skip]
-  let mut a = Matrix::from_vec(vec![
-    vec![1, 0],
-    vec![0, 1]
-  ]);
-    #[rustfmt::skip]
-  let b = Matrix::from_vec(vec![
-    vec![0, 0, 0, 0],
-    vec![0, 0, 1, 0],
-    vec![0, 0, 0, 1],
-    vec![0, 0, 0, 0],
-  ]);
-    a.origin = (2, 1);
-    assert_eq!(a.resolution_x(&b), 1);
-  }
-
-  #[test]
-  fn test_resolution_x_bounds_collision() {
-    let mut a = Matrix::from_vec(vec![
-      vec![0, 0, 0, 0],
-      vec![0, 0, 0, 0],
-      vec![1, 1, 1, 1],
-      vec![0, 0, 0, 0],
-    ]);
-    let b = Matrix::from_vec(vec![
-      vec![0, 0, 0, 0, 0, 0],
-      vec![0, 0, 0, 0, 0, 0],
-      vec![0, 1, 0, 0, 0, 0],
-      vec![0, 1, 0, 0, 0, 0],
-      vec![0, 1, 0, 0, 0, 0],
-      vec![0, 1, 0, 0, 0, 0],
-    ]);
-    // Out of bounds (max)
-    a.origin = (0, 2);
-    assert_eq!(a.resolution_x(&b), 2);
-  }
-
   #[test]
   fn test_min_y() {
     let a = Matrix::from_vec(vec![vec![1, 1, 1, 1]]);
@@ -317,97 +170,4 @@ mod test {
     assert_eq!(b.max_y(), Some(1));
     assert_eq!(c.max_y(), Some(2));
   }
-
-  #[test]
-  fn add_in_bounds() {
-    #[rustfmt::skip]
-  let mut a = Matrix::from_vec(vec![
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-  ]);
-    #[rustfmt::skip]
-  let mut b = Matrix::from_vec(vec![
-    vec![1, 1, 1],
-    vec![1, 1, 1]
-  ]);
-    #[rustfmt::skip]
-  let expected = Matrix::from_vec(vec![
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 1, 1, 1, 0],
-    vec![0, 0, 1, 1, 1, 0],
-    vec![0, 0, 0, 0, 0, 0],
-  ]);
-    b.origin = (2, 3);
-    a.add(&b);
-    assert_eq!(a.cells, expected.cells);
-  }
-
-  #[test]
-  fn add_in_bounds_2() {
-    #[rustfmt::skip]
-  let mut a = Matrix::from_vec(vec![
-    vec![0, 0, 0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0, 0, 0],
-  ]);
-    #[rustfmt::skip]
-  let mut b = Matrix::from_vec(vec![
-      vec![0, 0, 0, 0],
-      vec![1, 1, 1, 1],
-      vec![0, 0, 0, 0],
-      vec![0, 0, 0, 0]
-  ]);
-    #[rustfmt::skip]
-  let expected = Matrix::from_vec(vec![
-    vec![0, 0, 0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0, 0, 0],
-    vec![1, 1, 1, 1, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0, 0, 0],
-  ]);
-    a.origin = (2, 0);
-    b.origin = (2, 1);
-    a.add(&b);
-    assert_eq!(a.cells, expected.cells);
-  }
-
-  #[test]
-  fn add_test_bounds() {
-    #[rustfmt::skip]
-  let mut a = Matrix::from_vec(vec![
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-  ]);
-    #[rustfmt::skip]
-  let mut b = Matrix::from_vec(vec![
-    vec![1, 1, 1],
-    vec![1, 1, 1]
-  ]);
-    #[rustfmt::skip]
-  let expected = Matrix::from_vec(vec![
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 0, 0],
-    vec![0, 0, 0, 0, 1, 1],
-    vec![0, 0, 0, 0, 1, 1],
-  ]);
-    b.origin = (4, 4);
-    a.add(&b);
-    assert_eq!(a.cells, expected.cells);
-  }
 }
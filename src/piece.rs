@@ -1,16 +1,32 @@
 use crate::matrix::Matrix;
 
-#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PieceKind {
+  I,
+  J,
+  L,
+  O,
+  S,
+  T,
+  Z,
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct Piece {
   pub matrix: Matrix,
   pub at_rest: bool,
+  pub kind: PieceKind,
+  // Current SRS rotation state: 0 (spawn), 1 (R), 2 (2), 3 (L)
+  pub rotation_state: u8,
 }
 
 impl Piece {
-  pub fn new(matrix: Matrix) -> Piece {
+  pub fn new(kind: PieceKind, matrix: Matrix) -> Piece {
     Piece {
       matrix,
       at_rest: false,
+      kind,
+      rotation_state: 0,
     }
   }
 }
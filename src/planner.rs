@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::bitboard::PieceMask;
+use crate::board::Board;
+use crate::game::Cmd;
+use crate::kicks;
+use crate::matrix::Matrix;
+use crate::piece::{Piece, PieceKind};
+
+// A piece placement mid-flight: its origin and SRS rotation state.
+type State = (i8, i8, u8);
+
+fn collides(board: &Board, matrix: &Matrix) -> bool {
+  board.matrix.collides(&PieceMask::from(matrix))
+}
+
+pub(crate) fn shape_for_state(spawn_shape: &Matrix, state: u8) -> Matrix {
+  let mut shape = spawn_shape.clone();
+  for _ in 0..state {
+    shape = Matrix::rotate_right(&shape);
+  }
+  shape
+}
+
+// `piece.matrix` holds the piece's *current* shape, which is only the
+// rotation_state-0 (spawn) orientation the instant a piece spawns. Undo
+// `rotation_state` quarter-turns to recover the true spawn shape, so
+// `shape_for_state` can re-derive any orientation regardless of when or how
+// often `plan_to` (or `solver::best_placement`) is called relative to
+// spawning.
+pub(crate) fn spawn_shape(piece: &Piece) -> Matrix {
+  let undo_turns = (4 - piece.rotation_state % 4) % 4;
+  let mut shape = piece.matrix.clone();
+  for _ in 0..undo_turns {
+    shape = Matrix::rotate_right(&shape);
+  }
+  shape
+}
+
+fn is_supported(board: &Board, matrix: &Matrix) -> bool {
+  let mut below = matrix.clone();
+  below.origin.1 += 1;
+  collides(board, &below)
+}
+
+// The states reachable from `state` in a single Left, Right, SoftDrop, or
+// (SRS-kicked) Rotate move, paired with the command that produces them.
+fn neighbors(board: &Board, kind: PieceKind, spawn_shape: &Matrix, state: State) -> Vec<(State, Cmd)> {
+  let (x, y, r) = state;
+  let shape = shape_for_state(spawn_shape, r);
+  let mut moves = Vec::new();
+
+  for (dx, cmd) in [(-1, Cmd::Left), (1, Cmd::Right)] {
+    let mut matrix = shape.clone();
+    matrix.origin = (x + dx, y);
+    if !collides(board, &matrix) {
+      moves.push(((x + dx, y, r), cmd));
+    }
+  }
+
+  {
+    let mut matrix = shape.clone();
+    matrix.origin = (x, y + 1);
+    if !collides(board, &matrix) {
+      moves.push(((x, y + 1, r), Cmd::SoftDrop));
+    }
+  }
+
+  {
+    let to_r = (r + 1) % 4;
+    let rotated = Matrix::rotate_right(&shape);
+    for &(dx, dy) in kicks::offsets_for(kind, r, to_r) {
+      let mut candidate = rotated.clone();
+      candidate.origin = (x + dx, y + dy);
+      if !collides(board, &candidate) {
+        moves.push(((x + dx, y + dy, to_r), Cmd::Rotate));
+        break;
+      }
+    }
+  }
+
+  moves
+}
+
+// Breadth-first search over reachable piece states, starting from `piece`'s
+// current placement, returning the shortest command sequence that ends with
+// the piece resting at column `column` in rotation state `rotation`. Empty
+// if no such placement is reachable.
+pub fn plan_to(board: &Board, piece: &Piece, column: i8, rotation: u8) -> Vec<Cmd> {
+  let start: State = (
+    piece.matrix.origin.0,
+    piece.matrix.origin.1,
+    piece.rotation_state,
+  );
+  let rotation = rotation % 4;
+
+  let spawn = spawn_shape(piece);
+
+  let mut visited = HashSet::new();
+  let mut predecessor: HashMap<State, (State, Cmd)> = HashMap::new();
+  let mut frontier = VecDeque::new();
+  visited.insert(start);
+  frontier.push_back(start);
+
+  let mut target = None;
+  while let Some(state) = frontier.pop_front() {
+    let (x, y, r) = state;
+    if x == column && r == rotation {
+      let mut matrix = shape_for_state(&spawn, r);
+      matrix.origin = (x, y);
+      if is_supported(board, &matrix) {
+        target = Some(state);
+        break;
+      }
+    }
+
+    for (next_state, cmd) in neighbors(board, piece.kind, &spawn, state) {
+      if visited.insert(next_state) {
+        predecessor.insert(next_state, (state, cmd));
+        frontier.push_back(next_state);
+      }
+    }
+  }
+
+  let mut target = match target {
+    Some(target) => target,
+    None => return Vec::new(),
+  };
+
+  let mut path = Vec::new();
+  while target != start {
+    let (prev, cmd) = predecessor[&target];
+    path.push(cmd);
+    target = prev;
+  }
+  path.reverse();
+  path
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn o_piece(origin: (i8, i8)) -> Piece {
+    let mut piece = Piece::new(PieceKind::O, Matrix::from_vec(vec![vec![4, 4], vec![4, 4]]));
+    piece.matrix.origin = origin;
+    piece
+  }
+
+  #[test]
+  fn test_plan_to_routes_around_an_overhang() {
+    let mut board = Board::new(6, 4);
+    // A ceiling across columns 0-1 at the very top row: the piece can't slide
+    // under it until it first drops clear of that row in another column.
+    let mut overhang = Matrix::from_vec(vec![vec![9, 9]]);
+    overhang.origin = (0, 0);
+    board.matrix.add(&PieceMask::from(&overhang));
+
+    let piece = o_piece((2, 0));
+    let path = plan_to(&board, &piece, 0, 0);
+
+    assert_eq!(path.len(), 6);
+    assert_eq!(path.iter().filter(|&&c| c == Cmd::Left).count(), 2);
+    assert_eq!(path.iter().filter(|&&c| c == Cmd::SoftDrop).count(), 4);
+
+    // Replay the plan and confirm it never collides along the way.
+    let mut matrix = piece.matrix.clone();
+    for &cmd in &path {
+      let mut next = matrix.clone();
+      match cmd {
+        Cmd::Left => next.origin.0 -= 1,
+        Cmd::Right => next.origin.0 += 1,
+        Cmd::SoftDrop => next.origin.1 += 1,
+        Cmd::Rotate | Cmd::Drop => unreachable!("O-piece plan shouldn't need these"),
+      }
+      assert!(!collides(&board, &next));
+      matrix = next;
+    }
+    assert_eq!(matrix.origin, (0, 4));
+  }
+
+  #[test]
+  fn test_plan_to_returns_empty_when_unreachable() {
+    let mut board = Board::new(6, 4);
+    // Seal column 0 off entirely, top to bottom, so no placement there exists.
+    let mut wall = Matrix::from_vec(vec![vec![9]; 6]);
+    wall.origin = (0, 0);
+    board.matrix.add(&PieceMask::from(&wall));
+
+    let piece = o_piece((2, 0));
+    let path = plan_to(&board, &piece, 0, 0);
+
+    assert!(path.is_empty());
+  }
+}
@@ -0,0 +1,300 @@
+use crate::bitboard::{drop_origin, BitBoard, PieceMask};
+use crate::board::Board;
+use crate::matrix::Matrix;
+use crate::piece::Piece;
+use crate::planner;
+
+// Dellacherie feature weights.
+const LANDING_HEIGHT_WEIGHT: f64 = -4.500;
+const ROWS_CLEARED_WEIGHT: f64 = 3.418;
+const ROW_TRANSITIONS_WEIGHT: f64 = -3.218;
+const COLUMN_TRANSITIONS_WEIGHT: f64 = -9.349;
+const HOLES_WEIGHT: f64 = -7.899;
+const WELL_SUMS_WEIGHT: f64 = -3.386;
+
+/// A candidate hard drop considered by the autopilot: rest the piece in
+/// absolute SRS orientation `rotation_state` (0 = spawn, matching what
+/// `planner::plan_to` expects) with its pre-drop origin x at `column`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+  pub rotation_state: u8,
+  pub column: i8,
+  pub origin: (i8, i8),
+  pub score: f64,
+}
+
+// The local columns (inclusive) a shape actually fills, independent of its
+// bounding box — an "L" rotation's template can have empty edge columns.
+fn filled_column_range(shape: &Matrix) -> (i8, i8) {
+  let grid = shape.to_vec();
+  let mut min_j = None;
+  let mut max_j = None;
+  for row in &grid {
+    for (j, &cell) in row.iter().enumerate() {
+      if cell != 0 {
+        let j = j as i8;
+        min_j = Some(min_j.map_or(j, |m: i8| m.min(j)));
+        max_j = Some(max_j.map_or(j, |m: i8| m.max(j)));
+      }
+    }
+  }
+  (min_j.unwrap_or(0), max_j.unwrap_or(0))
+}
+
+// Every rotation state x column combination the piece can legally rest in,
+// each simulated as a hard drop and scored under the Dellacherie feature
+// set. `column` ranges over every board column the shape's actual filled
+// cells can reach, not just its bounding box's left edge, so wall-flush
+// placements with an empty edge column (e.g. a J/L/S/T/Z "R"/"L" rotation,
+// or a vertical I piece) are still considered. Rotation states are derived
+// from the piece's true spawn shape, so the result doesn't depend on
+// whatever orientation the piece currently happens to be in.
+fn candidates(board: &Board, piece: &Piece) -> Vec<Placement> {
+  let spawn = planner::spawn_shape(piece);
+  let board_col_count = board.matrix.col_count as i8;
+  let mut placements = Vec::new();
+
+  for rotation_state in 0..4 {
+    let shape = planner::shape_for_state(&spawn, rotation_state);
+    let (min_j, max_j) = filled_column_range(&shape);
+    let first_column = -min_j;
+    let last_column = board_col_count - 1 - max_j;
+    for column in first_column..=last_column {
+      let mut candidate = shape.clone();
+      candidate.origin = (column, 0);
+      if board.matrix.collides(&PieceMask::from(&candidate)) {
+        continue;
+      }
+      let origin = drop_origin(&board.matrix, &candidate);
+      candidate.origin = origin;
+
+      let mut landed = board.matrix.clone();
+      landed.add(&PieceMask::from(&candidate));
+      let rows_cleared = landed.clear_full_rows();
+      let score = score_placement(&candidate, rows_cleared, &landed);
+
+      placements.push(Placement {
+        rotation_state,
+        column,
+        origin,
+        score,
+      });
+    }
+  }
+
+  placements
+}
+
+// Keep the highest-scoring placement among every legal rotation x column.
+pub fn best_placement(board: &Board, piece: &Piece) -> Option<Placement> {
+  let mut best: Option<Placement> = None;
+  for candidate in candidates(board, piece) {
+    if best.as_ref().is_none_or(|b| candidate.score > b.score) {
+      best = Some(candidate);
+    }
+  }
+  best
+}
+
+fn score_placement(candidate: &Matrix, rows_cleared: u8, landed: &BitBoard) -> f64 {
+  let row_max = landed.row_count as i8 - 1;
+  let landing_row = candidate.max_y().unwrap_or(0);
+  let landing_height = (row_max - landing_row) as f64;
+  let grid = landed.to_vec();
+
+  landing_height * LANDING_HEIGHT_WEIGHT
+    + rows_cleared as f64 * ROWS_CLEARED_WEIGHT
+    + row_transitions(&grid) as f64 * ROW_TRANSITIONS_WEIGHT
+    + column_transitions(&grid) as f64 * COLUMN_TRANSITIONS_WEIGHT
+    + holes(&grid) as f64 * HOLES_WEIGHT
+    + well_sums(&grid) as f64 * WELL_SUMS_WEIGHT
+}
+
+// Count filled<->empty changes scanning each row left-to-right, treating
+// the outside walls as filled.
+fn row_transitions(grid: &[Vec<u8>]) -> u32 {
+  let mut total = 0;
+  for row in grid {
+    let mut prev_filled = true;
+    for &cell in row {
+      let filled = cell != 0;
+      if filled != prev_filled {
+        total += 1;
+      }
+      prev_filled = filled;
+    }
+    if !prev_filled {
+      total += 1;
+    }
+  }
+  total
+}
+
+// Count filled<->empty changes scanning each column top-to-bottom, treating
+// the floor as filled.
+fn column_transitions(grid: &[Vec<u8>]) -> u32 {
+  let col_count = grid.first().map_or(0, |row| row.len());
+  let mut total = 0;
+  for col in 0..col_count {
+    let mut prev_filled = false;
+    for row in grid {
+      let filled = row[col] != 0;
+      if filled != prev_filled {
+        total += 1;
+      }
+      prev_filled = filled;
+    }
+    if !prev_filled {
+      total += 1;
+    }
+  }
+  total
+}
+
+// Count empty cells that have at least one filled cell above them in the
+// same column.
+fn holes(grid: &[Vec<u8>]) -> u32 {
+  let col_count = grid.first().map_or(0, |row| row.len());
+  let mut total = 0;
+  for col in 0..col_count {
+    let mut seen_filled = false;
+    for row in grid {
+      if row[col] != 0 {
+        seen_filled = true;
+      } else if seen_filled {
+        total += 1;
+      }
+    }
+  }
+  total
+}
+
+// For each empty cell whose left and right neighbors (or walls) are filled,
+// add the cumulative depth of the contiguous well it belongs to.
+fn well_sums(grid: &[Vec<u8>]) -> u32 {
+  let col_count = grid.first().map_or(0, |row| row.len());
+  let mut total = 0;
+  for col in 0..col_count {
+    let mut depth = 0u32;
+    for row in grid {
+      if row[col] != 0 {
+        depth = 0;
+        continue;
+      }
+      let left_filled = col == 0 || row[col - 1] != 0;
+      let right_filled = col == col_count - 1 || row[col + 1] != 0;
+      if left_filled && right_filled {
+        depth += 1;
+        total += depth;
+      } else {
+        depth = 0;
+      }
+    }
+  }
+  total
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_row_transitions_empty_row() {
+    // Wall | 0 0 0 | wall -> transitions only at the two wall edges.
+    assert_eq!(row_transitions(&[vec![0, 0, 0]]), 2);
+  }
+
+  #[test]
+  fn test_row_transitions_counts_internal_changes() {
+    // Wall | 1 0 1 | wall -> two internal transitions, no wall transitions.
+    assert_eq!(row_transitions(&[vec![1, 0, 1]]), 2);
+  }
+
+  #[test]
+  fn test_holes_counts_covered_empties() {
+    let grid = vec![vec![1, 0], vec![0, 0]];
+    assert_eq!(holes(&grid), 1);
+  }
+
+  #[test]
+  fn test_well_sums_accumulates_depth() {
+    #[rustfmt::skip]
+    let grid = vec![
+      vec![1, 0, 1],
+      vec![1, 0, 1],
+    ];
+    assert_eq!(well_sums(&grid), 3);
+  }
+
+  #[test]
+  fn test_column_transitions_empty_column() {
+    // Top is not treated as filled, only the floor -> one transition at the bottom.
+    assert_eq!(column_transitions(&[vec![0], vec![0], vec![0]]), 1);
+  }
+
+  #[test]
+  fn test_column_transitions_counts_floor_and_internal() {
+    // gap / block / gap, floor filled -> transitions at each boundary plus the floor.
+    assert_eq!(column_transitions(&[vec![0], vec![1], vec![0]]), 3);
+  }
+
+  fn t_spawn_shape() -> Matrix {
+    #[rustfmt::skip]
+    let shape = Matrix::from_vec(vec![
+      vec![0, 6, 0],
+      vec![6, 6, 6],
+      vec![0, 0, 0],
+    ]);
+    shape
+  }
+
+  #[test]
+  fn test_candidates_reach_wall_flush_columns_for_every_rotation() {
+    use crate::piece::PieceKind;
+
+    let board = Board::new(6, 10);
+    let piece = Piece::new(PieceKind::T, t_spawn_shape());
+    let placements = candidates(&board, &piece);
+
+    // The "R" rotation's template is empty in its leftmost column, so
+    // reaching the board's left wall needs an origin one column left of the
+    // shape's bounding box -- the bug this guards against clamped `column`
+    // to never go below 0.
+    assert!(placements
+      .iter()
+      .any(|p| p.rotation_state == 1 && p.column == -1));
+    // The "L" rotation's template is empty in its rightmost column, so
+    // reaching the board's right wall needs an origin further right than
+    // its bounding box would suggest.
+    assert!(placements
+      .iter()
+      .any(|p| p.rotation_state == 3 && p.column == 8));
+  }
+
+  #[test]
+  fn test_candidates_are_independent_of_the_pieces_current_rotation_state() {
+    use crate::piece::PieceKind;
+
+    let board = Board::new(6, 10);
+
+    let mut at_spawn = Piece::new(PieceKind::T, t_spawn_shape());
+    at_spawn.matrix.origin = (3, 0);
+
+    // Same physical piece and board, but as if the player had already
+    // rotated it once (SRS state 1) before autopilot took over: `matrix` is
+    // the current (rotated) shape, `rotation_state` tracks that it's not
+    // spawn orientation. The set of placements considered must not change.
+    let mut rotated_once = Piece::new(PieceKind::T, Matrix::rotate_right(&t_spawn_shape()));
+    rotated_once.matrix.origin = (3, 0);
+    rotated_once.rotation_state = 1;
+
+    let from_spawn = candidates(&board, &at_spawn);
+    let from_rotated = candidates(&board, &rotated_once);
+
+    assert!(!from_spawn.is_empty());
+    assert_eq!(from_spawn.len(), from_rotated.len());
+    for placement in &from_spawn {
+      assert!(from_rotated.contains(placement));
+    }
+  }
+}